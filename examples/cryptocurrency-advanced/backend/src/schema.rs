@@ -15,18 +15,214 @@
 //! Cryptocurrency database schema.
 
 use exonum::{
-    crypto::Hash,
+    crypto::{Hash, PublicKey, Signature},
     merkledb::{
         access::{Access, FromAccess, RawAccessMut},
-        Group, ObjectHash, ProofListIndex, RawProofMapIndex,
+        BinaryValue, Group, ObjectHash, ProofEntry, ProofListIndex, RawProofMapIndex,
     },
     runtime::CallerAddress as Address,
 };
-use exonum_derive::{FromAccess, RequireArtifact};
+use exonum_derive::{BinaryValue, FromAccess, ObjectHash, RequireArtifact};
+use serde_derive::{Deserialize, Serialize};
+
+use std::fmt;
 
 use crate::{wallet::Wallet, INITIAL_BALANCE};
 use crate::transactions::TxSendApprove;
 
+/// A wallet balance amount, checked against overflow/underflow.
+///
+/// Raw `u64`/`i64` arithmetic on balances can silently wrap or panic on an oversized
+/// transfer; `Balance` forces every mutation through `checked_add`/`checked_sub` so that
+/// the failure surfaces as a `BalanceError` instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Balance(u64);
+
+impl Balance {
+    /// Wraps a raw amount as a `Balance`.
+    pub fn new(value: u64) -> Self {
+        Balance(value)
+    }
+
+    /// Returns the raw amount.
+    pub fn value(self) -> u64 {
+        self.0
+    }
+
+    /// Adds `amount`, failing on `u64` overflow.
+    pub fn checked_add(self, amount: u64) -> Result<Self, BalanceError> {
+        self.0
+            .checked_add(amount)
+            .map(Balance)
+            .ok_or(BalanceError::Overflow)
+    }
+
+    /// Subtracts `amount`, failing if the result would be negative.
+    pub fn checked_sub(self, amount: u64) -> Result<Self, BalanceError> {
+        self.0
+            .checked_sub(amount)
+            .map(Balance)
+            .ok_or(BalanceError::InsufficientFunds)
+    }
+}
+
+/// Errors produced by checked balance arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BalanceError {
+    /// The operation would have made the balance exceed `u64::MAX`.
+    Overflow,
+    /// The operation would have made the balance (or frozen balance) negative, or would
+    /// have made the frozen balance exceed the total balance.
+    InsufficientFunds,
+}
+
+impl fmt::Display for BalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BalanceError::Overflow => write!(f, "balance arithmetic overflowed"),
+            BalanceError::InsufficientFunds => write!(f, "insufficient funds"),
+        }
+    }
+}
+
+impl std::error::Error for BalanceError {}
+
+/// Proof that a specific approved transfer was actually received by its recipient.
+///
+/// The recipient (or the approver finalizing the transfer on their behalf) signs the
+/// message `tx_hash || to || amount_le` with their ed25519 secret key once the transfer
+/// is confirmed. A payer can hand this proof to a third party to demonstrate that the
+/// funds reached the recipient, which the hash-only `wallet_history` cannot do on its own.
+#[derive(Clone, Debug, Serialize, Deserialize, BinaryValue, ObjectHash)]
+#[binary_value(codec = "bincode")]
+pub struct PaymentProof {
+    /// Recipient of the approved transfer.
+    pub recipient: Address,
+    /// Amount that was transferred.
+    pub amount: u64,
+    /// Hash of the transaction that initiated the transfer.
+    pub tx_hash: Hash,
+    /// Ed25519 public key of the signer (the recipient or the approver), since an
+    /// `Address` is itself a one-way hash of this key and cannot be recovered from it.
+    pub signer: PublicKey,
+    /// Ed25519 signature over `tx_hash || to || amount_le`.
+    pub signature: Vec<u8>,
+}
+
+impl PaymentProof {
+    /// Builds the 72-byte message that gets signed: `tx_hash || to || amount_le`.
+    fn message(tx_hash: &Hash, recipient: &Address, amount: u64) -> Vec<u8> {
+        let mut message = Vec::with_capacity(72);
+        message.extend_from_slice(tx_hash.as_ref());
+        message.extend_from_slice(&recipient.to_bytes());
+        message.extend_from_slice(&amount.to_le_bytes());
+        message
+    }
+}
+
+/// A single record in a wallet's authenticated history: the transaction hash together
+/// with the wallet's balance immediately after that transaction was applied.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, BinaryValue, ObjectHash)]
+#[binary_value(codec = "bincode")]
+pub struct HistoryRecord {
+    /// Hash of the transaction that produced this record.
+    pub tx_hash: Hash,
+    /// Wallet balance right after the transaction was applied.
+    pub balance_after: u64,
+}
+
+/// What a `wallet_transactions` entry represents from the wallet's point of view.
+///
+/// Beyond `Create`/`Send`/`Receive`/`Approve`, this also has a `Fee` kind: the fee
+/// collector's wallet (added for network fees) is credited for a transfer it isn't a
+/// sender, recipient, or approver on, so it needs its own kind to be labeled correctly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WalletHistoryKind {
+    /// The wallet was created.
+    Create,
+    /// The wallet sent funds (it was the originator of the escrowed transfer).
+    Send,
+    /// The wallet received funds from an approved transfer.
+    Receive,
+    /// The wallet approved a transfer on someone else's behalf. Produced once the
+    /// approve transaction handler calls `record_approval` on the approver's wallet.
+    Approve,
+    /// The wallet collected the network fee from a transfer it was not a party to.
+    Fee,
+}
+
+/// A joined, client-friendly view of a `wallet_history` entry: the raw hash resolved
+/// against `confirmed_transaction` so callers don't need a second round trip to find
+/// out what it meant.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WalletHistoryEntry {
+    /// Hash of the underlying transaction.
+    pub tx_hash: Hash,
+    /// What kind of event this was for the wallet.
+    pub kind: WalletHistoryKind,
+    /// The other party involved, if any.
+    pub counterparty: Option<Address>,
+    /// Amount transferred, if this entry is a transfer.
+    pub amount: Option<u64>,
+    /// Wallet balance right after this entry was applied.
+    pub balance_after: u64,
+}
+
+/// Parameters for `create_send_approve_transaction`, replacing its long positional
+/// argument list so that future additions (an expiry, a memo, ...) become new struct
+/// fields instead of breaking every call site.
+#[derive(Clone, Debug)]
+pub struct SendApproveArgs {
+    /// Wallet of the sender, as fetched by the caller.
+    pub wallet: Wallet,
+    /// Amount to transfer to `to`.
+    pub amount: u64,
+    /// Fee charged to the sender on top of `amount`.
+    pub fee: u64,
+    /// Recipient of the transfer.
+    pub to: Address,
+    /// Address that must approve the transfer before it is finalized.
+    pub approver: Address,
+    /// Hash of the transaction carrying this request.
+    pub transaction: Hash,
+}
+
+/// Read-only preview of a `create_send_approve_transaction` call: the sender's
+/// resulting frozen and available balances, computed without mutating state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SendApproveEstimate {
+    /// Sender's frozen balance after the transfer is created.
+    pub frozen_balance: u64,
+    /// Sender's available (unfrozen) balance after the transfer is created.
+    pub available_balance: u64,
+}
+
+/// Errors produced by `estimate_send_approve`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SendApproveError {
+    /// The sender cannot cover `amount + fee`.
+    Balance(BalanceError),
+    /// The sender, recipient, or approver wallet does not exist.
+    UnknownWallet(Address),
+}
+
+impl fmt::Display for SendApproveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SendApproveError::Balance(err) => write!(f, "{}", err),
+            SendApproveError::UnknownWallet(address) => write!(f, "unknown wallet: {:?}", address),
+        }
+    }
+}
+
+impl std::error::Error for SendApproveError {}
+
+impl From<BalanceError> for SendApproveError {
+    fn from(err: BalanceError) -> Self {
+        SendApproveError::Balance(err)
+    }
+}
+
 /// Database schema for the cryptocurrency.
 ///
 /// Note that the schema is crate-private, but it has a public part.
@@ -36,7 +232,7 @@ pub(crate) struct SchemaImpl<T: Access> {
     #[from_access(flatten)]
     pub public: Schema<T>,
     /// History for specific wallets.
-    pub wallet_history: Group<T, Address, ProofListIndex<T::Base, Hash>>,
+    pub wallet_history: Group<T, Address, ProofListIndex<T::Base, HistoryRecord>>,
 }
 
 /// Public part of the cryptocurrency schema.
@@ -47,6 +243,11 @@ pub struct Schema<T: Access> {
     pub wallets: RawProofMapIndex<T::Base, Address, Wallet>,
     /// Map of approval transactions hash to infromation about the corresponding approval transaction
     pub confirmed_transaction: RawProofMapIndex<T::Base, Hash, TxSendApprove>,
+    /// Map of approval transaction hash to the payment proof produced once the transfer
+    /// is finalized, letting a payer prove to a third party that the funds were received.
+    pub payment_proofs: RawProofMapIndex<T::Base, Hash, PaymentProof>,
+    /// Running total of network fees collected from approved transfers.
+    pub total_fees: ProofEntry<T::Base, u64>,
 }
 
 impl<T: Access> SchemaImpl<T> {
@@ -61,6 +262,124 @@ impl<T: Access> SchemaImpl<T> {
     pub fn confirmed(&self, hash: Hash) -> Option<TxSendApprove> {
         self.public.confirmed_transaction.get(&hash)
     }
+
+    /// Returns the payment proof for a finalized transfer, if one has been produced.
+    pub fn payment_proof(&self, tx_hash: Hash) -> Option<PaymentProof> {
+        self.public.payment_proofs.get(&tx_hash)
+    }
+
+    /// Total network fees collected so far from approved transfers.
+    pub fn total_fees_collected(&self) -> u64 {
+        self.public.total_fees.get().unwrap_or_default()
+    }
+
+    /// Joins the wallet's authenticated `wallet_history` with `confirmed_transaction` to
+    /// produce a structured view of what each entry meant for this wallet. The
+    /// `ProofListIndex` stays the authenticated source, so a light client can still
+    /// request a Merkle proof for the same entries alongside this view.
+    pub fn wallet_transactions(&self, address: Address) -> Vec<WalletHistoryEntry> {
+        let history = self.wallet_history.get(&address);
+        history
+            .iter()
+            .map(|record| match self.public.confirmed_transaction.get(&record.tx_hash) {
+                Some(tx) => {
+                    let (kind, counterparty) = if tx.from == address {
+                        (WalletHistoryKind::Send, Some(tx.to))
+                    } else if tx.to == address {
+                        (WalletHistoryKind::Receive, Some(tx.from))
+                    } else if tx.approver == address {
+                        (WalletHistoryKind::Approve, Some(tx.from))
+                    } else {
+                        // The fee collector is credited via `collect_fee` but isn't a
+                        // party recorded on the transaction itself.
+                        (WalletHistoryKind::Fee, Some(tx.from))
+                    };
+                    let amount = if kind == WalletHistoryKind::Fee {
+                        Some(tx.fee)
+                    } else {
+                        Some(tx.amount)
+                    };
+                    WalletHistoryEntry {
+                        tx_hash: record.tx_hash,
+                        kind,
+                        counterparty,
+                        amount,
+                        balance_after: record.balance_after,
+                    }
+                }
+                None => WalletHistoryEntry {
+                    tx_hash: record.tx_hash,
+                    kind: WalletHistoryKind::Create,
+                    counterparty: None,
+                    amount: None,
+                    balance_after: record.balance_after,
+                },
+            })
+            .collect()
+    }
+
+    /// Verifies that the stored payment proof for `tx_hash` is a valid signature, made
+    /// by the key recorded on the proof, over the reconstructed `tx_hash || to ||
+    /// amount_le` message — and that the signer is actually the recipient or approver
+    /// of the confirmed transfer, and that the proof's own `recipient`/`amount` fields
+    /// agree with the confirmed transaction, so a proof can't be forged by signing with
+    /// an unrelated key or by attaching mismatched decoration to a real signature.
+    pub fn verify_payment_proof(&self, tx_hash: Hash) -> bool {
+        let proof = match self.public.payment_proofs.get(&tx_hash) {
+            Some(proof) => proof,
+            None => return false,
+        };
+        let confirmed = match self.public.confirmed_transaction.get(&tx_hash) {
+            Some(confirmed) => confirmed,
+            None => return false,
+        };
+        if proof.recipient != confirmed.to || proof.amount != confirmed.amount {
+            return false;
+        }
+
+        let signer_address = Address::from_key(proof.signer);
+        if signer_address != confirmed.to && signer_address != confirmed.approver {
+            return false;
+        }
+
+        let message = PaymentProof::message(&tx_hash, &confirmed.to, confirmed.amount);
+        let signature = match Signature::from_slice(&proof.signature) {
+            Some(signature) => signature,
+            None => return false,
+        };
+        exonum::crypto::verify(&signature, &message, &proof.signer)
+    }
+
+    /// Dry-runs `create_send_approve_transaction` without mutating state: checks that
+    /// the sender, recipient, and approver wallets exist and that the sender can cover
+    /// `amount + fee`, then reports the resulting frozen/available balances. Lets wallet
+    /// UIs validate and preview a transfer before submitting it for real.
+    pub fn estimate_send_approve(&self, args: &SendApproveArgs) -> Result<SendApproveEstimate, SendApproveError> {
+        let sender = self
+            .public
+            .wallets
+            .get(&args.wallet.owner)
+            .ok_or(SendApproveError::UnknownWallet(args.wallet.owner))?;
+        self.public
+            .wallets
+            .get(&args.to)
+            .ok_or(SendApproveError::UnknownWallet(args.to))?;
+        self.public
+            .wallets
+            .get(&args.approver)
+            .ok_or(SendApproveError::UnknownWallet(args.approver))?;
+
+        let escrowed = Balance::new(args.amount).checked_add(args.fee)?;
+        let frozen_balance = Balance::new(sender.frozen_balance).checked_add(escrowed.value())?;
+        if frozen_balance.value() > sender.balance {
+            return Err(BalanceError::InsufficientFunds.into());
+        }
+
+        Ok(SendApproveEstimate {
+            frozen_balance: frozen_balance.value(),
+            available_balance: sender.balance - frozen_balance.value(),
+        })
+    }
 }
 
 impl<T> SchemaImpl<T>
@@ -68,71 +387,111 @@ where
     T: Access,
     T::Base: RawAccessMut,
 {
-    pub fn create_send_approve_transaction(&mut self,
-                                           wallet: Wallet,
-                                           amount: u64,
-                                           to: Address,
-                                           approver: Address,
-                                           transaction: Hash) {
-        self.increase_frozen_balance(wallet,  amount as i64, transaction);
-        self.public.confirmed_transaction.put(&transaction, TxSendApprove::new(to, amount, approver))
+    pub fn create_send_approve_transaction(&mut self, args: SendApproveArgs) -> Result<(), BalanceError> {
+        let SendApproveArgs { wallet, amount, fee, to, approver, transaction } = args;
+        let from = wallet.owner;
+        let escrowed = Balance::new(amount).checked_add(fee)?;
+        self.increase_frozen_balance(wallet, escrowed.value(), transaction)?;
+        self.public.confirmed_transaction.put(&transaction, TxSendApprove::new(from, to, amount, fee, approver));
+        Ok(())
     }
 
-    /// Increases frozen of the wallet and append new record to its history.
+    /// Increases frozen balance of the wallet and appends a new record to its history.
+    /// Fails if the frozen balance would exceed the wallet's total balance.
     pub fn increase_frozen_balance(&mut self,
                                  wallet: Wallet,
-                                 frozen_balance_change: i64,
-                                 transaction: Hash) {
+                                 frozen_balance_change: u64,
+                                 transaction: Hash) -> Result<(), BalanceError> {
+        let frozen_balance = Balance::new(wallet.frozen_balance).checked_add(frozen_balance_change)?;
+        if frozen_balance.value() > wallet.balance {
+            return Err(BalanceError::InsufficientFunds);
+        }
+
         let mut history = self.wallet_history.get(&wallet.owner);
-        history.push(transaction);
+        history.push(HistoryRecord { tx_hash: transaction, balance_after: wallet.balance });
         let history_hash = history.object_hash();
 
-        let wallet_frozen_balance = (wallet.frozen_balance as i64);
-        let wallet = wallet.set_frozen_balance(( wallet_frozen_balance + frozen_balance_change) as u64, &history_hash);
+        let wallet = wallet.set_frozen_balance(frozen_balance.value(), &history_hash);
 
         let wallet_key = wallet.owner;
         self.public.wallets.put(&wallet_key, wallet);
+        Ok(())
     }
 
-    /// Decreases frozen of the wallet and append new record to its history.
+    /// Decreases frozen balance of the wallet and appends a new record to its history.
+    /// Called on the sender's wallet once a transfer is approved, unfreezing the full
+    /// escrowed `amount + fee`; the recipient and fee-collector wallets are credited
+    /// separately via `increase_wallet_balance` and `collect_fee`.
     pub fn decrease_frozen_balance(&mut self,
                                    wallet: Wallet,
                                    frozen_balance_change: u64,
-                                   transaction: Hash) {
+                                   transaction: Hash) -> Result<(), BalanceError> {
+        let frozen_balance = Balance::new(wallet.frozen_balance).checked_sub(frozen_balance_change)?;
+        let balance = Balance::new(wallet.balance).checked_sub(frozen_balance_change)?;
+
         let mut history = self.wallet_history.get(&wallet.owner);
-        history.push(transaction);
+        history.push(HistoryRecord { tx_hash: transaction, balance_after: balance.value() });
         let history_hash = history.object_hash();
 
-        let wallet_frozen_balance = wallet.frozen_balance;
+        let wallet = wallet.set_frozen_balance(frozen_balance.value(), &history_hash);
+        let wallet = wallet.set_balance(balance.value(), &history_hash);
 
-        let dif_froz = wallet_frozen_balance - frozen_balance_change;
-        let dif_bal = wallet.balance - frozen_balance_change;
+        let wallet_key = wallet.owner;
+        self.public.wallets.put(&wallet_key, wallet);
+        Ok(())
+    }
+
+    /// Increases balance of the wallet and appends a new record to its history.
+    pub fn increase_wallet_balance(&mut self, wallet: Wallet, amount: u64, transaction: Hash) -> Result<(), BalanceError> {
+        let balance = Balance::new(wallet.balance).checked_add(amount)?;
 
-        let wallet = wallet.set_frozen_balance(dif_froz, &history_hash);
-        let wallet = wallet.set_balance(dif_bal, &history_hash);
+        let mut history = self.wallet_history.get(&wallet.owner);
+        history.push(HistoryRecord { tx_hash: transaction, balance_after: balance.value() });
+        let history_hash = history.object_hash();
 
+        let wallet = wallet.set_balance(balance.value(), &history_hash);
         let wallet_key = wallet.owner;
         self.public.wallets.put(&wallet_key, wallet);
+        Ok(())
     }
 
-    /// Increases balance of the wallet and append new record to its history.
-    pub fn increase_wallet_balance(&mut self, wallet: Wallet, amount: u64, transaction: Hash) {
+    /// Decreases balance of the wallet and appends a new record to its history. Fails if
+    /// the result would drop the balance below the wallet's frozen balance.
+    pub fn decrease_wallet_balance(&mut self, wallet: Wallet, amount: u64, transaction: Hash) -> Result<(), BalanceError> {
+        let balance = Balance::new(wallet.balance).checked_sub(amount)?;
+        if balance.value() < wallet.frozen_balance {
+            return Err(BalanceError::InsufficientFunds);
+        }
+
         let mut history = self.wallet_history.get(&wallet.owner);
-        history.push(transaction);
+        history.push(HistoryRecord { tx_hash: transaction, balance_after: balance.value() });
         let history_hash = history.object_hash();
-        let balance = wallet.balance;
-        let wallet = wallet.set_balance(balance + amount, &history_hash);
+
+        let wallet = wallet.set_balance(balance.value(), &history_hash);
         let wallet_key = wallet.owner;
         self.public.wallets.put(&wallet_key, wallet);
+        Ok(())
     }
 
-    /// Decreases balance of the wallet and append new record to its history.
-    pub fn decrease_wallet_balance(&mut self, wallet: Wallet, amount: u64, transaction: Hash) {
-        let mut history = self.wallet_history.get(&wallet.owner);
-        history.push(transaction);
+    /// Credits the fee from an approved transfer to the fee-collector wallet and adds it
+    /// to the running `total_fees` counter.
+    pub fn collect_fee(&mut self, fee_collector: Wallet, fee: u64, transaction: Hash) -> Result<(), BalanceError> {
+        self.increase_wallet_balance(fee_collector, fee, transaction)?;
+        let total_fees = Balance::new(self.public.total_fees.get().unwrap_or_default()).checked_add(fee)?;
+        self.public.total_fees.set(total_fees.value());
+        Ok(())
+    }
+
+    /// Appends a history record to the approver's wallet marking that they approved a
+    /// transfer, without changing their balance. The approve transaction handler calls
+    /// this alongside `decrease_frozen_balance` so `wallet_transactions` can classify
+    /// the entry as `WalletHistoryKind::Approve` on the approver's side.
+    pub fn record_approval(&mut self, approver: Wallet, transaction: Hash) {
+        let mut history = self.wallet_history.get(&approver.owner);
+        history.push(HistoryRecord { tx_hash: transaction, balance_after: approver.balance });
         let history_hash = history.object_hash();
-        let balance = wallet.balance;
-        let wallet = wallet.set_balance(balance - amount, &history_hash);
+
+        let wallet = approver.set_balance(approver.balance, &history_hash);
         let wallet_key = wallet.owner;
         self.public.wallets.put(&wallet_key, wallet);
     }
@@ -140,9 +499,94 @@ where
     /// Creates a new wallet and append first record to its history.
     pub fn create_wallet(&mut self, key: Address, name: &str, transaction: Hash) {
         let mut history = self.wallet_history.get(&key);
-        history.push(transaction);
+        history.push(HistoryRecord { tx_hash: transaction, balance_after: INITIAL_BALANCE });
         let history_hash = history.object_hash();
         let wallet = Wallet::new(key, name, INITIAL_BALANCE, 0, history.len(), &history_hash);
         self.public.wallets.put(&key, wallet);
     }
+
+    /// Records the payment proof for a finalized transfer. The approve transaction
+    /// handler calls this alongside `decrease_frozen_balance`, passing the recipient's
+    /// (or approver's) public key and their ed25519 signature over
+    /// `tx_hash || to || amount_le`, both submitted with the finalizing transaction.
+    pub fn record_payment_proof(
+        &mut self,
+        tx_hash: Hash,
+        recipient: Address,
+        amount: u64,
+        signer: PublicKey,
+        signature: Vec<u8>,
+    ) {
+        let proof = PaymentProof {
+            recipient,
+            amount,
+            tx_hash,
+            signer,
+            signature,
+        };
+        self.public.payment_proofs.put(&tx_hash, proof);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exonum::merkledb::{Database, TemporaryDB};
+
+    fn wallet(owner: Address, balance: u64, frozen_balance: u64) -> Wallet {
+        Wallet::new(owner, "test", balance, frozen_balance, 0, &Hash::default())
+    }
+
+    #[test]
+    fn checked_add_overflow_boundary() {
+        assert_eq!(
+            Balance::new(u64::MAX - 1).checked_add(1),
+            Ok(Balance::new(u64::MAX))
+        );
+        assert_eq!(
+            Balance::new(u64::MAX).checked_add(1),
+            Err(BalanceError::Overflow)
+        );
+    }
+
+    #[test]
+    fn checked_sub_underflow_boundary() {
+        assert_eq!(Balance::new(1).checked_sub(1), Ok(Balance::new(0)));
+        assert_eq!(
+            Balance::new(0).checked_sub(1),
+            Err(BalanceError::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn increase_frozen_balance_rejects_frozen_exceeding_balance() {
+        let db = TemporaryDB::new();
+        let fork = db.fork();
+        let mut schema = SchemaImpl::new(&fork);
+        let owner = Address::default();
+
+        assert!(schema
+            .increase_frozen_balance(wallet(owner, 100, 0), 100, Hash::default())
+            .is_ok());
+        assert_eq!(
+            schema.increase_frozen_balance(wallet(owner, 100, 0), 101, Hash::default()),
+            Err(BalanceError::InsufficientFunds)
+        );
+    }
+
+    #[test]
+    fn decrease_wallet_balance_rejects_dropping_below_frozen_balance() {
+        let db = TemporaryDB::new();
+        let fork = db.fork();
+        let mut schema = SchemaImpl::new(&fork);
+        let owner = Address::default();
+
+        assert!(schema
+            .decrease_wallet_balance(wallet(owner, 100, 50), 50, Hash::default())
+            .is_ok());
+        assert_eq!(
+            schema.decrease_wallet_balance(wallet(owner, 100, 50), 51, Hash::default()),
+            Err(BalanceError::InsufficientFunds)
+        );
+    }
 }